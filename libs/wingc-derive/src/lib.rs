@@ -0,0 +1,182 @@
+//! Prototype derive macro for generating AST walking code for `wingc::visit`.
+//!
+//! **Status: not wired into `wingc` yet.** `wingc` does not depend on this crate, and
+//! `#[derive(Visitable)]` is not applied to any of the real node types in `ast.rs` - the
+//! hand-written `visit_*` functions in `wingc::visit` are still what actually runs. The only
+//! place this derive is exercised today is `tests/visitable.rs`, against stand-in node types
+//! defined in that test file, not the real AST.
+//!
+//! The intended shape, once wired up: `#[derive(Visitable)]` on `Scope`, `Stmt`, `StmtKind`,
+//! `Expr`, `ExprKind`, `Class`, `Reference`, `Literal`, etc. would emit a call into the
+//! matching `Visit` method for each field whose type is itself `Visitable`, or a `Vec<T>` /
+//! `Option<T>` / `IndexMap<_, T>` of one, and the hand-written `visit_*` bodies in `visit.rs`
+//! would be deleted in favor of `node.walk(v)`.
+//!
+//! That integration isn't just a drop-in swap, though - two things this macro does not yet
+//! model, both added to `Visit` after this prototype was written:
+//! - `visit_nested_scope`/`visit_nested_function` (the shallow-vs-deep traversal hooks):
+//!   `walk_fields` always calls the "deep" method (`visit_scope`, `visit_function_definition`)
+//!   for a recognized field, so a naive switch-over would silently stop routing constructor/
+//!   method/closure bodies through the nested hooks, regressing shallow traversal.
+//! - `visit_type`/`visit_symbol`: `TypeAnnotation` and `Symbol` fields aren't in
+//!   `visit_method_for`'s table below, so they'd be silently skipped instead of visited.
+//!
+//! Closing those gaps needs either field-level annotations (e.g. `#[visit(nested)]`) or a
+//! smarter convention than "match by field type name" - real integration work, not done here.
+
+use proc_macro::TokenStream;
+use proc_macro2::Ident;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Fields, GenericArgument, PathArguments, Type};
+
+/// Maps an AST node's type name to the method on `Visit` that walks it. Most types follow the
+/// `Foo` -> `visit_foo` convention, with a few exceptions (like `ArgList` -> `visit_args`).
+/// Every mapping is listed explicitly and nothing is inferred: a type that is not in the
+/// table gets `None`, and fields of that type are not visited.
+fn visit_method_for(type_name: &str) -> Option<Ident> {
+	let method = match type_name {
+		"Scope" => "visit_scope",
+		"Stmt" => "visit_stmt",
+		"Class" => "visit_class",
+		"Constructor" => "visit_constructor",
+		"Expr" => "visit_expr",
+		"Literal" => "visit_literal",
+		"Reference" => "visit_reference",
+		"FunctionDefinition" => "visit_function_definition",
+		"ArgList" => "visit_args",
+		_ => return None,
+	};
+	Some(format_ident!("{}", method))
+}
+
+/// Unwraps `Vec<T>` / `Option<T>` / `IndexMap<_, T>` down to their innermost element type, so
+/// `visit_method_for` can be matched against the thing that's actually being visited.
+fn innermost_type(ty: &Type) -> &Type {
+	if let Type::Path(type_path) = ty {
+		if let Some(segment) = type_path.path.segments.last() {
+			if matches!(segment.ident.to_string().as_str(), "Vec" | "Option" | "IndexMap" | "Box") {
+				if let PathArguments::AngleBracketed(args) = &segment.arguments {
+					if let Some(GenericArgument::Type(inner)) = args.args.last() {
+						return innermost_type(inner);
+					}
+				}
+			}
+		}
+	}
+	ty
+}
+
+fn type_name(ty: &Type) -> Option<String> {
+	match ty {
+		Type::Path(type_path) => type_path.path.segments.last().map(|s| s.ident.to_string()),
+		_ => None,
+	}
+}
+
+fn is_outer_type(ty: &Type, name: &str) -> bool {
+	matches!(ty, Type::Path(p) if p.path.segments.last().map(|s| s.ident == name).unwrap_or(false))
+}
+
+/// Generates the body that visits every `Visitable` field of a struct/variant.
+///
+/// `field_access` must return, for the field at a given index, an expression of exactly the
+/// field's declared type *by reference* (`&Option<T>`, `&Vec<T>`, `&IndexMap<_, T>`, or `&T`).
+/// Struct fields pass `&self.<name>`; enum variant fields pass the match-ergonomics binding
+/// (already a reference, since the outer `match` is on `self: &Self`).
+fn walk_fields(fields: &Fields, field_access: impl Fn(usize, &syn::Field) -> proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+	let mut stmts = Vec::new();
+	for (i, field) in fields.iter().enumerate() {
+		let Some(name) = type_name(innermost_type(&field.ty)) else {
+			continue;
+		};
+		let Some(method) = visit_method_for(&name) else {
+			continue;
+		};
+		let access = field_access(i, field);
+		let is_option = is_outer_type(&field.ty, "Option");
+		let is_vec = is_outer_type(&field.ty, "Vec");
+		let is_index_map = is_outer_type(&field.ty, "IndexMap");
+		stmts.push(if is_option {
+			quote! {
+				if let Some(inner) = #access {
+					v.#method(inner);
+				}
+			}
+		} else if is_vec {
+			quote! {
+				for item in #access {
+					v.#method(item);
+				}
+			}
+		} else if is_index_map {
+			// `IndexMap<K, V>` iterates as `(&K, &V)` pairs; we only want the values, matching
+			// the hand-written `visit_*` functions (e.g. `for val in fields.values() { ... }`).
+			quote! {
+				for item in (#access).values() {
+					v.#method(item);
+				}
+			}
+		} else {
+			quote! {
+				v.#method(#access);
+			}
+		});
+	}
+	quote! { #(#stmts)* }
+}
+
+/// `#[derive(Visitable)]`: implements `crate::visit::Visitable::walk`, which calls the matching
+/// `Visit` method for each recognized field instead of hand-matching each variant's fields.
+/// The deriving crate must provide `crate::visit::{Visit, Visitable}`.
+#[proc_macro_derive(Visitable)]
+pub fn derive_visitable(input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as DeriveInput);
+	let name = &input.ident;
+
+	let body = match &input.data {
+		Data::Struct(data) => walk_fields(&data.fields, |i, field| match &field.ident {
+			Some(ident) => quote! { &self.#ident },
+			None => {
+				let index = syn::Index::from(i);
+				quote! { &self.#index }
+			}
+		}),
+		Data::Enum(data) => {
+			let arms = data.variants.iter().map(|variant| {
+				let variant_ident = &variant.ident;
+				let bindings: Vec<_> = (0..variant.fields.len())
+					.map(|i| format_ident!("field_{}", i))
+					.collect();
+				let pattern = match &variant.fields {
+					Fields::Named(named) => {
+						let names = named.named.iter().map(|f| f.ident.as_ref().unwrap());
+						quote! { Self::#variant_ident { #(#names: #bindings),* } }
+					}
+					Fields::Unnamed(_) => quote! { Self::#variant_ident( #(#bindings),* ) },
+					Fields::Unit => quote! { Self::#variant_ident },
+				};
+				let body = walk_fields(&variant.fields, |i, _| {
+					let binding = &bindings[i];
+					quote! { #binding }
+				});
+				quote! { #pattern => { #body } }
+			});
+			quote! {
+				match self {
+					#(#arms)*
+				}
+			}
+		}
+		Data::Union(_) => panic!("#[derive(Visitable)] does not support unions"),
+	};
+
+	let expanded = quote! {
+		impl crate::visit::Visitable for #name {
+			#[allow(unused_variables)]
+			fn walk<'ast, V: crate::visit::Visit<'ast> + ?Sized>(&'ast self, v: &mut V) {
+				#body
+			}
+		}
+	};
+	expanded.into()
+}
@@ -1,6 +1,6 @@
 use crate::ast::{
-	ArgList, Class, Constructor, Expr, ExprKind, FunctionDefinition, InterpolatedStringPart, Literal, Reference, Scope,
-	Stmt, StmtKind,
+	ArgList, CatchBlock, Class, Constructor, Elif, Expr, ExprKind, FunctionDefinition, InterpolatedStringPart, Literal,
+	Reference, Scope, Stmt, StmtKind, Symbol, TypeAnnotation,
 };
 
 /// Visitor pattern inspired by implementation from https://docs.rs/syn/latest/syn/visit/index.html
@@ -25,9 +25,9 @@ use crate::ast::{
 /// }
 /// ```
 ///
-/// TODO: Can we code-generate this based on data in `ast.rs`?
-/// TODO: Provide a VisitMut trait that allows for mutation of the AST nodes
-/// (each method would accept a `&mut node` instead of `&node`)
+/// TODO: Can we code-generate this based on data in `ast.rs`? There's a prototype derive macro
+/// for this in the sibling `wingc-derive` crate, but it isn't wired up here yet - see that
+/// crate's doc comment for what's missing before these hand-written functions can be replaced.
 pub trait Visit<'ast> {
 	fn visit_scope(&mut self, node: &'ast Scope) {
 		visit_scope(self, node);
@@ -56,6 +56,34 @@ pub trait Visit<'ast> {
 	fn visit_args(&mut self, node: &'ast ArgList) {
 		visit_args(self, node);
 	}
+	/// Called for the body of a constructor or function/method definition, instead of
+	/// `visit_scope` directly. The default recurses like any other scope, but overriding it
+	/// (with an empty body) lets a visitor stop at item boundaries and skip walking into
+	/// constructor/method/closure bodies entirely - useful for lints that only care about
+	/// top-level item shapes (e.g. class declarations) and shouldn't pay to walk every body.
+	fn visit_nested_scope(&mut self, node: &'ast Scope) {
+		visit_scope(self, node);
+	}
+	/// Called when descending into a function/method definition nested inside another item
+	/// (a class method or a closure expression), instead of `visit_function_definition`
+	/// directly. The default recurses; overriding it with an empty body stops the walk from
+	/// entering nested function bodies, matching the shallow-vs-deep visit split above.
+	fn visit_nested_function(&mut self, node: &'ast FunctionDefinition) {
+		visit_function_definition(self, node);
+	}
+	/// Called for every type annotation reachable from an expression or statement (variable,
+	/// array/struct/map/set literal types, struct member types, ...), so analyses like
+	/// type-usage collection don't have to special-case each place a type can appear.
+	fn visit_type(&mut self, node: &'ast TypeAnnotation) {
+		visit_type(self, node);
+	}
+	/// Called for the identifiers of `bring` statements, variable definitions, loop iterators,
+	/// struct members and enum values, so analyses like unused-import detection or symbol
+	/// renaming can hook a single method for them. Other identifiers (references, property
+	/// names, struct/enum/class names, ...) are not passed to it.
+	fn visit_symbol(&mut self, node: &'ast Symbol) {
+		visit_symbol(self, node);
+	}
 }
 
 pub fn visit_scope<'ast, V>(v: &mut V, node: &'ast Scope)
@@ -72,23 +100,30 @@ where
 	V: Visit<'ast> + ?Sized,
 {
 	match &node.kind {
-		StmtKind::Bring {
-			module_name: _,
-			identifier: _,
-		} => {}
+		StmtKind::Bring { module_name, identifier } => {
+			v.visit_symbol(module_name);
+			if let Some(identifier) = identifier {
+				v.visit_symbol(identifier);
+			}
+		}
 		StmtKind::VariableDef {
 			reassignable: _,
-			var_name: _,
+			var_name,
 			initial_value,
-			type_: _,
+			type_,
 		} => {
+			v.visit_symbol(var_name);
 			v.visit_expr(initial_value);
+			if let Some(type_) = type_ {
+				v.visit_type(type_);
+			}
 		}
 		StmtKind::ForLoop {
-			iterator: _,
+			iterator,
 			iterable,
 			statements,
 		} => {
+			v.visit_symbol(iterator);
 			v.visit_expr(iterable);
 			v.visit_scope(statements);
 		}
@@ -133,9 +168,18 @@ where
 		StmtKind::Struct {
 			name: _,
 			extends: _,
-			members: _,
-		} => {}
-		StmtKind::Enum { name: _, values: _ } => {}
+			members,
+		} => {
+			for (member_name, member_type) in members {
+				v.visit_symbol(member_name);
+				v.visit_type(member_type);
+			}
+		}
+		StmtKind::Enum { name: _, values } => {
+			for value in values {
+				v.visit_symbol(value);
+			}
+		}
 		StmtKind::TryCatch {
 			try_statements,
 			catch_block,
@@ -158,7 +202,7 @@ where
 {
 	v.visit_constructor(&node.constructor);
 	for method in &node.methods {
-		v.visit_function_definition(&method.1);
+		v.visit_nested_function(&method.1);
 	}
 }
 
@@ -166,7 +210,7 @@ pub fn visit_constructor<'ast, V>(v: &mut V, node: &'ast Constructor)
 where
 	V: Visit<'ast> + ?Sized,
 {
-	v.visit_scope(&node.statements);
+	v.visit_nested_scope(&node.statements);
 }
 
 pub fn visit_expr<'ast, V>(v: &mut V, node: &'ast Expr)
@@ -202,28 +246,40 @@ where
 			v.visit_expr(left);
 			v.visit_expr(right);
 		}
-		ExprKind::ArrayLiteral { type_: _, items } => {
+		ExprKind::ArrayLiteral { type_, items } => {
+			if let Some(type_) = type_ {
+				v.visit_type(type_);
+			}
 			for item in items {
 				v.visit_expr(item);
 			}
 		}
-		ExprKind::StructLiteral { type_: _, fields } => {
+		ExprKind::StructLiteral { type_, fields } => {
+			if let Some(type_) = type_ {
+				v.visit_type(type_);
+			}
 			for val in fields.values() {
 				v.visit_expr(val);
 			}
 		}
-		ExprKind::MapLiteral { type_: _, fields } => {
+		ExprKind::MapLiteral { type_, fields } => {
+			if let Some(type_) = type_ {
+				v.visit_type(type_);
+			}
 			for val in fields.values() {
 				v.visit_expr(val);
 			}
 		}
-		ExprKind::SetLiteral { type_: _, items } => {
+		ExprKind::SetLiteral { type_, items } => {
+			if let Some(type_) = type_ {
+				v.visit_type(type_);
+			}
 			for item in items {
 				v.visit_expr(item);
 			}
 		}
 		ExprKind::FunctionClosure(def) => {
-			v.visit_function_definition(def);
+			v.visit_nested_function(def);
 		}
 	}
 }
@@ -263,7 +319,7 @@ pub fn visit_function_definition<'ast, V>(v: &mut V, node: &'ast FunctionDefinit
 where
 	V: Visit<'ast> + ?Sized,
 {
-	v.visit_scope(&node.statements);
+	v.visit_nested_scope(&node.statements);
 }
 
 pub fn visit_args<'ast, V>(v: &mut V, node: &'ast ArgList)
@@ -276,4 +332,532 @@ where
 	for arg in &node.named_args {
 		v.visit_expr(&arg.1);
 	}
-}
\ No newline at end of file
+}
+
+pub fn visit_type<'ast, V>(_v: &mut V, _node: &'ast TypeAnnotation)
+where
+	V: Visit<'ast> + ?Sized,
+{
+	// Type annotations are leaves as far as the visitor is concerned today; there are no
+	// nested AST nodes to recurse into here.
+}
+
+pub fn visit_symbol<'ast, V>(_v: &mut V, _node: &'ast Symbol)
+where
+	V: Visit<'ast> + ?Sized,
+{
+	// Identifiers are leaves; nothing to recurse into.
+}
+
+/// Visitor pattern for mutating the AST in place, mirroring `Visit` above but taking
+/// `&mut` references to each node instead of shared references.
+///
+/// This is the AST-rewriting counterpart of `Visit`, intended for passes that need to
+/// rewrite a tree in place (desugaring, constant folding, macro-style expansion) without
+/// manually reconstructing every node. As with `Visit`, the default implementation of each
+/// method just recurses into the node's children by calling the matching `walk_*_mut`
+/// function, so you only need to override the methods for the nodes you care about.
+///
+/// Unlike `Visit`, it has no `visit_nested_*`, `visit_type` or `visit_symbol` methods:
+/// constructor, method and closure bodies are always walked, and type annotations and
+/// identifiers are not visited.
+pub trait VisitMut<'ast> {
+	fn visit_scope_mut(&mut self, node: &'ast mut Scope) {
+		walk_scope_mut(self, node);
+	}
+	fn visit_stmt_mut(&mut self, node: &'ast mut Stmt) {
+		walk_stmt_mut(self, node);
+	}
+	fn visit_class_mut(&mut self, node: &'ast mut Class) {
+		walk_class_mut(self, node);
+	}
+	fn visit_constructor_mut(&mut self, node: &'ast mut Constructor) {
+		walk_constructor_mut(self, node);
+	}
+	fn visit_expr_mut(&mut self, node: &'ast mut Expr) {
+		walk_expr_mut(self, node);
+	}
+	fn visit_literal_mut(&mut self, node: &'ast mut Literal) {
+		walk_literal_mut(self, node);
+	}
+	fn visit_reference_mut(&mut self, node: &'ast mut Reference) {
+		walk_reference_mut(self, node);
+	}
+	fn visit_function_definition_mut(&mut self, node: &'ast mut FunctionDefinition) {
+		walk_function_definition_mut(self, node);
+	}
+	fn visit_args_mut(&mut self, node: &'ast mut ArgList) {
+		walk_args_mut(self, node);
+	}
+}
+
+pub fn walk_scope_mut<'ast, V>(v: &mut V, node: &'ast mut Scope)
+where
+	V: VisitMut<'ast> + ?Sized,
+{
+	for stmt in &mut node.statements {
+		v.visit_stmt_mut(stmt);
+	}
+}
+
+pub fn walk_stmt_mut<'ast, V>(v: &mut V, node: &'ast mut Stmt)
+where
+	V: VisitMut<'ast> + ?Sized,
+{
+	match &mut node.kind {
+		StmtKind::Bring {
+			module_name: _,
+			identifier: _,
+		} => {}
+		StmtKind::VariableDef {
+			reassignable: _,
+			var_name: _,
+			initial_value,
+			type_: _,
+		} => {
+			v.visit_expr_mut(initial_value);
+		}
+		StmtKind::ForLoop {
+			iterator: _,
+			iterable,
+			statements,
+		} => {
+			v.visit_expr_mut(iterable);
+			v.visit_scope_mut(statements);
+		}
+		StmtKind::While { condition, statements } => {
+			v.visit_expr_mut(condition);
+			v.visit_scope_mut(statements);
+		}
+		StmtKind::If {
+			condition,
+			statements,
+			elif_statements,
+			else_statements,
+		} => {
+			v.visit_expr_mut(condition);
+			v.visit_scope_mut(statements);
+			for elif in elif_statements {
+				v.visit_expr_mut(&mut elif.condition);
+				v.visit_scope_mut(&mut elif.statements);
+			}
+			if let Some(statements) = else_statements {
+				v.visit_scope_mut(statements);
+			}
+		}
+		StmtKind::Expression(expr) => {
+			v.visit_expr_mut(expr);
+		}
+		StmtKind::Assignment { variable, value } => {
+			v.visit_reference_mut(variable);
+			v.visit_expr_mut(value);
+		}
+		StmtKind::Return(expr) => {
+			if let Some(expr) = expr {
+				v.visit_expr_mut(expr);
+			}
+		}
+		StmtKind::Scope(scope) => {
+			v.visit_scope_mut(scope);
+		}
+		StmtKind::Class(class) => {
+			v.visit_class_mut(class);
+		}
+		StmtKind::Struct {
+			name: _,
+			extends: _,
+			members: _,
+		} => {}
+		StmtKind::Enum { name: _, values: _ } => {}
+		StmtKind::TryCatch {
+			try_statements,
+			catch_block,
+			finally_statements,
+		} => {
+			v.visit_scope_mut(try_statements);
+			if let Some(catch_block) = catch_block {
+				v.visit_scope_mut(&mut catch_block.statements);
+			}
+			if let Some(finally_statements) = finally_statements {
+				v.visit_scope_mut(finally_statements);
+			}
+		}
+	}
+}
+
+pub fn walk_class_mut<'ast, V>(v: &mut V, node: &'ast mut Class)
+where
+	V: VisitMut<'ast> + ?Sized,
+{
+	v.visit_constructor_mut(&mut node.constructor);
+	for method in &mut node.methods {
+		v.visit_function_definition_mut(&mut method.1);
+	}
+}
+
+pub fn walk_constructor_mut<'ast, V>(v: &mut V, node: &'ast mut Constructor)
+where
+	V: VisitMut<'ast> + ?Sized,
+{
+	v.visit_scope_mut(&mut node.statements);
+}
+
+pub fn walk_expr_mut<'ast, V>(v: &mut V, node: &'ast mut Expr)
+where
+	V: VisitMut<'ast> + ?Sized,
+{
+	match &mut node.kind {
+		ExprKind::New {
+			class: _,
+			obj_id: _,
+			obj_scope,
+			arg_list,
+		} => {
+			if let Some(scope) = obj_scope {
+				v.visit_expr_mut(scope);
+			}
+			v.visit_args_mut(arg_list);
+		}
+		ExprKind::Literal(lit) => {
+			v.visit_literal_mut(lit);
+		}
+		ExprKind::Reference(ref_) => {
+			v.visit_reference_mut(ref_);
+		}
+		ExprKind::Call { function, arg_list } => {
+			v.visit_expr_mut(function);
+			v.visit_args_mut(arg_list);
+		}
+		ExprKind::Unary { op: _, exp } => {
+			v.visit_expr_mut(exp);
+		}
+		ExprKind::Binary { op: _, left, right } => {
+			v.visit_expr_mut(left);
+			v.visit_expr_mut(right);
+		}
+		ExprKind::ArrayLiteral { type_: _, items } => {
+			for item in items {
+				v.visit_expr_mut(item);
+			}
+		}
+		ExprKind::StructLiteral { type_: _, fields } => {
+			for val in fields.values_mut() {
+				v.visit_expr_mut(val);
+			}
+		}
+		ExprKind::MapLiteral { type_: _, fields } => {
+			for val in fields.values_mut() {
+				v.visit_expr_mut(val);
+			}
+		}
+		ExprKind::SetLiteral { type_: _, items } => {
+			for item in items {
+				v.visit_expr_mut(item);
+			}
+		}
+		ExprKind::FunctionClosure(def) => {
+			v.visit_function_definition_mut(def);
+		}
+	}
+}
+
+pub fn walk_literal_mut<'ast, V>(v: &mut V, node: &'ast mut Literal)
+where
+	V: VisitMut<'ast> + ?Sized,
+{
+	match node {
+		Literal::InterpolatedString(interpolated_str) => {
+			for part in &mut interpolated_str.parts {
+				if let InterpolatedStringPart::Expr(exp) = part {
+					v.visit_expr_mut(exp);
+				}
+			}
+		}
+		Literal::Boolean(_) => {}
+		Literal::Number(_) => {}
+		Literal::Duration(_) => {}
+		Literal::String(_) => {}
+	}
+}
+
+pub fn walk_reference_mut<'ast, V>(v: &mut V, node: &'ast mut Reference)
+where
+	V: VisitMut<'ast> + ?Sized,
+{
+	match node {
+		Reference::NestedIdentifier { property: _, object } => {
+			v.visit_expr_mut(object);
+		}
+		Reference::Identifier(_) => {}
+	}
+}
+
+pub fn walk_function_definition_mut<'ast, V>(v: &mut V, node: &'ast mut FunctionDefinition)
+where
+	V: VisitMut<'ast> + ?Sized,
+{
+	v.visit_scope_mut(&mut node.statements);
+}
+
+pub fn walk_args_mut<'ast, V>(v: &mut V, node: &'ast mut ArgList)
+where
+	V: VisitMut<'ast> + ?Sized,
+{
+	for arg in &mut node.pos_args {
+		v.visit_expr_mut(arg);
+	}
+	for arg in &mut node.named_args {
+		v.visit_expr_mut(&mut arg.1);
+	}
+}
+
+/// Structural-transform counterpart of `Visit`/`VisitMut`: each method consumes a node by
+/// value and returns a (possibly different) owned node, rebuilding it from folded children.
+///
+/// Unlike `VisitMut`, a `Fold` pass can change the shape of the tree: a `fold_stmt` override
+/// can return a different `Stmt` than it was given, and the default `fold_scope` maps a
+/// `Vec<Stmt>` one-for-one, so overriding it is the hook for passes that splice in extra
+/// statements or drop some - something `VisitMut` can't express since it only ever gets a
+/// `&mut` to each existing element, never the `Vec` itself.
+pub trait Fold {
+	fn fold_scope(&mut self, node: Scope) -> Scope {
+		fold_scope(self, node)
+	}
+	fn fold_stmt(&mut self, node: Stmt) -> Stmt {
+		fold_stmt(self, node)
+	}
+	fn fold_class(&mut self, node: Class) -> Class {
+		fold_class(self, node)
+	}
+	fn fold_constructor(&mut self, node: Constructor) -> Constructor {
+		fold_constructor(self, node)
+	}
+	fn fold_expr(&mut self, node: Expr) -> Expr {
+		fold_expr(self, node)
+	}
+	fn fold_literal(&mut self, node: Literal) -> Literal {
+		fold_literal(self, node)
+	}
+	fn fold_reference(&mut self, node: Reference) -> Reference {
+		fold_reference(self, node)
+	}
+	fn fold_function_definition(&mut self, node: FunctionDefinition) -> FunctionDefinition {
+		fold_function_definition(self, node)
+	}
+	fn fold_args(&mut self, node: ArgList) -> ArgList {
+		fold_args(self, node)
+	}
+}
+
+pub fn fold_scope<F>(f: &mut F, node: Scope) -> Scope
+where
+	F: Fold + ?Sized,
+{
+	let statements = node.statements.into_iter().map(|stmt| f.fold_stmt(stmt)).collect();
+	Scope { statements, ..node }
+}
+
+pub fn fold_stmt<F>(f: &mut F, node: Stmt) -> Stmt
+where
+	F: Fold + ?Sized,
+{
+	let kind = match node.kind {
+		StmtKind::Bring { module_name, identifier } => StmtKind::Bring { module_name, identifier },
+		StmtKind::VariableDef {
+			reassignable,
+			var_name,
+			initial_value,
+			type_,
+		} => StmtKind::VariableDef {
+			reassignable,
+			var_name,
+			initial_value: f.fold_expr(initial_value),
+			type_,
+		},
+		StmtKind::ForLoop {
+			iterator,
+			iterable,
+			statements,
+		} => StmtKind::ForLoop {
+			iterator,
+			iterable: f.fold_expr(iterable),
+			statements: f.fold_scope(statements),
+		},
+		StmtKind::While { condition, statements } => StmtKind::While {
+			condition: f.fold_expr(condition),
+			statements: f.fold_scope(statements),
+		},
+		StmtKind::If {
+			condition,
+			statements,
+			elif_statements,
+			else_statements,
+		} => StmtKind::If {
+			condition: f.fold_expr(condition),
+			statements: f.fold_scope(statements),
+			elif_statements: elif_statements
+				.into_iter()
+				.map(|elif| Elif {
+					condition: f.fold_expr(elif.condition),
+					statements: f.fold_scope(elif.statements),
+					..elif
+				})
+				.collect(),
+			else_statements: else_statements.map(|statements| f.fold_scope(statements)),
+		},
+		StmtKind::Expression(expr) => StmtKind::Expression(f.fold_expr(expr)),
+		StmtKind::Assignment { variable, value } => StmtKind::Assignment {
+			variable: f.fold_reference(variable),
+			value: f.fold_expr(value),
+		},
+		StmtKind::Return(expr) => StmtKind::Return(expr.map(|expr| f.fold_expr(expr))),
+		StmtKind::Scope(scope) => StmtKind::Scope(f.fold_scope(scope)),
+		StmtKind::Class(class) => StmtKind::Class(f.fold_class(class)),
+		StmtKind::Struct { name, extends, members } => StmtKind::Struct { name, extends, members },
+		StmtKind::Enum { name, values } => StmtKind::Enum { name, values },
+		StmtKind::TryCatch {
+			try_statements,
+			catch_block,
+			finally_statements,
+		} => StmtKind::TryCatch {
+			try_statements: f.fold_scope(try_statements),
+			catch_block: catch_block.map(|catch_block| CatchBlock {
+				statements: f.fold_scope(catch_block.statements),
+				..catch_block
+			}),
+			finally_statements: finally_statements.map(|statements| f.fold_scope(statements)),
+		},
+	};
+	Stmt { kind, ..node }
+}
+
+pub fn fold_class<F>(f: &mut F, node: Class) -> Class
+where
+	F: Fold + ?Sized,
+{
+	let constructor = f.fold_constructor(node.constructor);
+	let methods = node
+		.methods
+		.into_iter()
+		.map(|(name, def)| (name, f.fold_function_definition(def)))
+		.collect();
+	Class {
+		constructor,
+		methods,
+		..node
+	}
+}
+
+pub fn fold_constructor<F>(f: &mut F, node: Constructor) -> Constructor
+where
+	F: Fold + ?Sized,
+{
+	let statements = f.fold_scope(node.statements);
+	Constructor { statements, ..node }
+}
+
+pub fn fold_expr<F>(f: &mut F, node: Expr) -> Expr
+where
+	F: Fold + ?Sized,
+{
+	let kind = match node.kind {
+		ExprKind::New {
+			class,
+			obj_id,
+			obj_scope,
+			arg_list,
+		} => ExprKind::New {
+			class,
+			obj_id,
+			obj_scope: obj_scope.map(|scope| Box::new(f.fold_expr(*scope))),
+			arg_list: f.fold_args(arg_list),
+		},
+		ExprKind::Literal(lit) => ExprKind::Literal(f.fold_literal(lit)),
+		ExprKind::Reference(reference) => ExprKind::Reference(f.fold_reference(reference)),
+		ExprKind::Call { function, arg_list } => ExprKind::Call {
+			function: Box::new(f.fold_expr(*function)),
+			arg_list: f.fold_args(arg_list),
+		},
+		ExprKind::Unary { op, exp } => ExprKind::Unary {
+			op,
+			exp: Box::new(f.fold_expr(*exp)),
+		},
+		ExprKind::Binary { op, left, right } => ExprKind::Binary {
+			op,
+			left: Box::new(f.fold_expr(*left)),
+			right: Box::new(f.fold_expr(*right)),
+		},
+		ExprKind::ArrayLiteral { type_, items } => ExprKind::ArrayLiteral {
+			type_,
+			items: items.into_iter().map(|item| f.fold_expr(item)).collect(),
+		},
+		ExprKind::StructLiteral { type_, fields } => ExprKind::StructLiteral {
+			type_,
+			fields: fields.into_iter().map(|(name, val)| (name, f.fold_expr(val))).collect(),
+		},
+		ExprKind::MapLiteral { type_, fields } => ExprKind::MapLiteral {
+			type_,
+			fields: fields.into_iter().map(|(name, val)| (name, f.fold_expr(val))).collect(),
+		},
+		ExprKind::SetLiteral { type_, items } => ExprKind::SetLiteral {
+			type_,
+			items: items.into_iter().map(|item| f.fold_expr(item)).collect(),
+		},
+		ExprKind::FunctionClosure(def) => ExprKind::FunctionClosure(f.fold_function_definition(def)),
+	};
+	Expr { kind, ..node }
+}
+
+pub fn fold_literal<F>(f: &mut F, node: Literal) -> Literal
+where
+	F: Fold + ?Sized,
+{
+	match node {
+		Literal::InterpolatedString(mut interpolated_str) => {
+			interpolated_str.parts = interpolated_str
+				.parts
+				.into_iter()
+				.map(|part| match part {
+					InterpolatedStringPart::Expr(exp) => InterpolatedStringPart::Expr(f.fold_expr(exp)),
+					other => other,
+				})
+				.collect();
+			Literal::InterpolatedString(interpolated_str)
+		}
+		other => other,
+	}
+}
+
+pub fn fold_reference<F>(f: &mut F, node: Reference) -> Reference
+where
+	F: Fold + ?Sized,
+{
+	match node {
+		Reference::NestedIdentifier { property, object } => Reference::NestedIdentifier {
+			property,
+			object: Box::new(f.fold_expr(*object)),
+		},
+		Reference::Identifier(sym) => Reference::Identifier(sym),
+	}
+}
+
+pub fn fold_function_definition<F>(f: &mut F, node: FunctionDefinition) -> FunctionDefinition
+where
+	F: Fold + ?Sized,
+{
+	let statements = f.fold_scope(node.statements);
+	FunctionDefinition { statements, ..node }
+}
+
+pub fn fold_args<F>(f: &mut F, node: ArgList) -> ArgList
+where
+	F: Fold + ?Sized,
+{
+	let pos_args = node.pos_args.into_iter().map(|arg| f.fold_expr(arg)).collect();
+	let named_args = node
+		.named_args
+		.into_iter()
+		.map(|(name, arg)| (name, f.fold_expr(arg)))
+		.collect();
+	ArgList { pos_args, named_args, ..node }
+}